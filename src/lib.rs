@@ -54,6 +54,15 @@ const RE: f64 = 6378137.0;
 /// Circumference of the Earth
 const CE: f64 = 2.0f64 * PI * RE;
 
+/// Maximum latitude the web mercator projection can represent, `atan(sinh(pi)) * 180 / pi`
+pub const MAX_LATITUDE: f64 = 85.05112877980659;
+
+/// Clamps a geographical coordinate into the range the web mercator projection can represent:
+/// longitude to `[-180, 180]` and latitude to `[-MAX_LATITUDE, MAX_LATITUDE]`
+pub fn truncate_lnglat(lng: f64, lat: f64) -> LngLat {
+    LngLat { lng: lng.clamp(-180.0, 180.0), lat: lat.clamp(-MAX_LATITUDE, MAX_LATITUDE) }
+}
+
 /// Represents a tile on a map
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Tile {
@@ -63,16 +72,41 @@ pub struct Tile {
 }
 
 impl Tile {
-    /// Creates a new Tile object with the specified x, y, and zoom level
+    /// Creates a new Tile object with the specified x, y, and zoom level, using the
+    /// web mercator (XYZ) pyramid shape
     pub fn new(x: i32, y: i32, z: i32) -> Self {
-        let (lo, hi) = minmax(z);
-        if !(lo <= x && x <= hi) || !(lo <= y && y <= hi) {
+        Tile::new_with_projection(x, y, z, Projection::WebMercator)
+    }
+
+    /// Creates a new Tile object, validating x/y against the pyramid shape of the given
+    /// projection: web mercator is a square `2^z` grid, while the geodetic pyramid has
+    /// twice as many columns as rows at every zoom
+    pub fn new_with_projection(x: i32, y: i32, z: i32, projection: Projection) -> Self {
+        let (lo_x, hi_x) = minmax_x(z, projection);
+        let (lo_y, hi_y) = minmax_y(z);
+        if !(lo_x <= x && x <= hi_x) || !(lo_y <= y && y <= hi_y) {
             panic!("require tile x and y to be within the range (0, 2 ** zoom)");
         }
         Tile { x, y, z }
     }
 }
 
+/// Returns the minimum and maximum `x` values for a tile at the given zoom level and projection
+fn minmax_x(z: i32, projection: Projection) -> (i32, i32) {
+    match projection {
+        Projection::WebMercator => minmax(z),
+        // The geodetic level-zero grid is 2 columns wide, so it has one more column
+        // doubling than the square mercator pyramid at the same zoom.
+        Projection::Geodetic => (0, 2_i32.pow((z + 1) as u32) - 1),
+    }
+}
+
+/// Returns the minimum and maximum `y` values for a tile at the given zoom level; both
+/// projections use a single-row level zero, so this doesn't vary by projection
+fn minmax_y(z: i32) -> (i32, i32) {
+    minmax(z)
+}
+
 /// Returns the minimum and maximum values for a tile at the given zoom level
 fn minmax(z: i32) -> (i32, i32) {
     let max_value = 2_i32.pow(z as u32);
@@ -119,64 +153,446 @@ pub struct Bbox {
     pub top: f64,
 }
 
+/// Selects the tile projection used by the `_with_projection` variants of `ul`, `bounds`,
+/// `xy_bounds`, `convert_xy`, and `convert_lng_lat`; the plain functions use web mercator
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Projection {
+    /// Spherical web mercator, the de-facto standard for XYZ/TMS slippy maps
+    WebMercator,
+    /// Plate carrée (EPSG:4326), a 2x1 level-zero grid that's linear in degrees
+    Geodetic,
+}
+
 /// Calculates the upper-left geographical coordinates of a given tile
 pub fn ul(tile: Tile) -> LngLat {
-    let z2 = 2.0_f64.powf(tile.z as f64);
-    let lon_deg = tile.x as f64 / z2 * 360.0 - 180.0;
-    let lat_rad = (PI * (1.0 - 2.0 * tile.y as f64 / z2)).sinh().atan();
-    let lat_deg = lat_rad.to_degrees();
+    ul_with_projection(tile, Projection::WebMercator)
+}
+
+/// Calculates the upper-left geographical coordinates of a given tile under the given projection
+pub fn ul_with_projection(tile: Tile, projection: Projection) -> LngLat {
+    corner_with_projection(tile.x, tile.y, tile.z, projection)
+}
 
-    LngLat { lng: lon_deg, lat: lat_deg }
+fn corner_with_projection(x: i32, y: i32, z: i32, projection: Projection) -> LngLat {
+    match projection {
+        Projection::WebMercator => {
+            let z2 = 2.0_f64.powf(z as f64);
+            let lon_deg = x as f64 / z2 * 360.0 - 180.0;
+            let lat_rad = (PI * (1.0 - 2.0 * y as f64 / z2)).sinh().atan();
+            let lat_deg = lat_rad.to_degrees();
+
+            LngLat { lng: lon_deg, lat: lat_deg }
+        }
+        Projection::Geodetic => {
+            let z2 = 2.0_f64.powf(z as f64);
+            let lon_deg = x as f64 * 360.0 / (2.0 * z2) - 180.0;
+            let lat_deg = 90.0 - y as f64 * 180.0 / z2;
+
+            LngLat { lng: lon_deg, lat: lat_deg }
+        }
+    }
 }
 
 /// Calculates the bounding box of a given tile in geographical coordinates
 pub fn bounds(tile: Tile) -> LngLatBbox {
-    let z2 = 2.0_f64.powf(tile.z as f64);
-
-    let west = tile.x as f64 / z2 * 360.0 - 180.0;
-    let north_rad = (PI * (1.0 - 2.0 * tile.y as f64 / z2)).sinh().atan();
-    let north = north_rad.to_degrees();
+    bounds_with_projection(tile, Projection::WebMercator)
+}
 
-    let east = (tile.x + 1) as f64 / z2 * 360.0 - 180.0;
-    let south_rad = (PI * (1.0 - 2.0 * (tile.y + 1) as f64 / z2)).sinh().atan();
-    let south = south_rad.to_degrees();
+/// Calculates the bounding box of a given tile in geographical coordinates under the given projection
+pub fn bounds_with_projection(tile: Tile, projection: Projection) -> LngLatBbox {
+    let LngLat { lng: west, lat: north } = corner_with_projection(tile.x, tile.y, tile.z, projection);
+    let LngLat { lng: east, lat: south } =
+        corner_with_projection(tile.x + 1, tile.y + 1, tile.z, projection);
 
     LngLatBbox { west, south, east, north }
 }
 
 /// Calculates the bounding box of a given tile in web mercator projected coordinates
 pub fn xy_bounds(tile: Tile) -> Bbox {
-    let tile_size = CE / 2.0_f64.powf(tile.z as f64);
-    let left = tile.x as f64 * tile_size - CE / 2.0;
-    let right = left + tile_size;
-    let top = CE / 2.0 - tile.y as f64 * tile_size;
-    let bottom = top - tile_size;
+    xy_bounds_with_projection(tile, Projection::WebMercator)
+}
+
+/// Calculates the bounding box of a given tile in projected coordinates under the given projection
+pub fn xy_bounds_with_projection(tile: Tile, projection: Projection) -> Bbox {
+    match projection {
+        Projection::WebMercator => {
+            let tile_size = CE / 2.0_f64.powf(tile.z as f64);
+            let left = tile.x as f64 * tile_size - CE / 2.0;
+            let right = left + tile_size;
+            let top = CE / 2.0 - tile.y as f64 * tile_size;
+            let bottom = top - tile_size;
+
+            Bbox { left, bottom, right, top }
+        }
+        Projection::Geodetic => {
+            let LngLatBbox { west, south, east, north } = bounds_with_projection(tile, projection);
 
-    Bbox { left, bottom, right, top }
+            Bbox { left: west, bottom: south, right: east, top: north }
+        }
+    }
 }
 
 /// Converts geographical coordinates (LngLat) to web mercator projected coordinates (XY)
 pub fn convert_xy(lng_lat: LngLat) -> XY {
-    let x = RE * lng_lat.lng.to_radians();
+    convert_xy_with_projection(lng_lat, Projection::WebMercator)
+}
 
-    let y: f64;
-    if lng_lat.lat <= -90.0 {
-        y = f64::NEG_INFINITY;
-    } else if lng_lat.lat >= 90.0 {
-        y = f64::INFINITY;
-    } else {
-        y = RE * ((PI * 0.25) + (0.5 * lng_lat.lat.to_radians())).tan().ln();
-    }
+/// Converts geographical coordinates (LngLat) to projected coordinates (XY) under the given projection
+pub fn convert_xy_with_projection(lng_lat: LngLat, projection: Projection) -> XY {
+    match projection {
+        Projection::WebMercator => {
+            let x = RE * lng_lat.lng.to_radians();
 
-    XY { x, y }
+            let y: f64;
+            if lng_lat.lat <= -90.0 {
+                y = f64::NEG_INFINITY;
+            } else if lng_lat.lat >= 90.0 {
+                y = f64::INFINITY;
+            } else {
+                y = RE * ((PI * 0.25) + (0.5 * lng_lat.lat.to_radians())).tan().ln();
+            }
+
+            XY { x, y }
+        }
+        Projection::Geodetic => XY { x: lng_lat.lng, y: lng_lat.lat },
+    }
 }
 
 /// Converts web mercator projected coordinates (XY) to geographical coordinates (LngLat)
 pub fn convert_lng_lat(xy: XY) -> LngLat {
-    let lng = xy.x * R2D / RE;
-    let lat = ((PI * 0.5) - 2.0 * (-xy.y / RE).exp().atan()) * R2D;
+    convert_lng_lat_with_projection(xy, Projection::WebMercator)
+}
+
+/// Converts projected coordinates (XY) to geographical coordinates (LngLat) under the given projection
+pub fn convert_lng_lat_with_projection(xy: XY, projection: Projection) -> LngLat {
+    match projection {
+        Projection::WebMercator => {
+            let lng = xy.x * R2D / RE;
+            let lat = ((PI * 0.5) - 2.0 * (-xy.y / RE).exp().atan()) * R2D;
+
+            LngLat { lng, lat }
+        }
+        Projection::Geodetic => LngLat { lng: xy.x, lat: xy.y },
+    }
+}
+
+/// Finds the tile containing a geographical point at the given zoom level
+pub fn tile(lng: f64, lat: f64, z: i32) -> Tile {
+    tile_with_truncate(lng, lat, z, false)
+}
+
+/// Finds the tile containing a geographical point at the given zoom level, optionally
+/// truncating the point into the web-mercator-valid range first via [`truncate_lnglat`]
+pub fn tile_with_truncate(lng: f64, lat: f64, z: i32, truncate: bool) -> Tile {
+    let (lng, lat) = if truncate {
+        let ll = truncate_lnglat(lng, lat);
+        (ll.lng, ll.lat)
+    } else {
+        (lng, lat)
+    };
+
+    let z2 = 2.0_f64.powf(z as f64);
+    let lat_rad = lat.to_radians();
+
+    let mut x = (lng / 360.0 + 0.5) * z2;
+    let mut y = (0.5 - ((PI * 0.25 + 0.5 * lat_rad).tan()).ln() / (2.0 * PI)) * z2;
+
+    let (lo, hi) = minmax(z);
+    x = x.floor();
+    y = y.floor();
+
+    let x = (x as i32).clamp(lo, hi);
+    let y = (y as i32).clamp(lo, hi);
+
+    Tile::new(x, y, z)
+}
+
+/// Returns the quadkey string addressing a tile, interleaving the bits of x and y
+/// from the most-significant zoom bit down
+pub fn quadkey(tile: Tile) -> String {
+    let mut qk = String::new();
+
+    for i in (0..tile.z).rev() {
+        let digit = ((tile.x >> i) & 1) + 2 * ((tile.y >> i) & 1);
+        qk.push_str(&digit.to_string());
+    }
+
+    qk
+}
+
+/// Converts a quadkey string back into the tile it addresses
+pub fn quadkey_to_tile(qk: &str) -> Tile {
+    if qk.is_empty() {
+        return Tile::new(0, 0, 0);
+    }
+
+    let z = qk.len() as i32;
+    let mut x = 0;
+    let mut y = 0;
+
+    for (i, c) in qk.chars().enumerate() {
+        let bit = z - 1 - i as i32;
+        let digit = c.to_digit(4).expect("quadkey digits must be in the range 0-3") as i32;
+
+        if digit & 1 != 0 {
+            x |= 1 << bit;
+        }
+        if digit & 2 != 0 {
+            y |= 1 << bit;
+        }
+    }
+
+    Tile::new(x, y, z)
+}
+
+/// Default tile edge length in pixels, matching the de-facto XYZ/TMS convention
+pub const DEFAULT_TILE_SIZE: f64 = 256.0;
+
+/// Identifies which edge of the tile pyramid a `y` coordinate is measured from
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Scheme {
+    /// Origin at the top-left, `y` increasing southward (Google/XYZ convention)
+    Xyz,
+    /// Origin at the bottom-left, `y` increasing northward (OSGeo TMS convention)
+    Tms,
+}
+
+/// Flips a tile's `y` coordinate between the XYZ and TMS schemes, since the two
+/// conventions disagree on which edge of the pyramid `y` is measured from
+pub fn flip_y(tile: Tile) -> Tile {
+    let (_, hi) = minmax(tile.z);
+    Tile::new(tile.x, hi - tile.y, tile.z)
+}
+
+/// Returns the ground resolution (meters per pixel at the equator) at the given zoom
+/// level, for a pyramid built from tiles of `tile_size` pixels
+pub fn resolution(z: i32, tile_size: f64) -> f64 {
+    CE / (tile_size * 2.0_f64.powf(z as f64))
+}
+
+/// Returns the smallest zoom level whose resolution is less than or equal to the
+/// requested pixel size
+pub fn zoom_for_pixel_size(px: f64, tile_size: f64) -> i32 {
+    let mut z = 0;
+    while z < 32 && resolution(z, tile_size) > px {
+        z += 1;
+    }
+    z
+}
+
+/// Converts a web mercator XY point into pixel coordinates at the given zoom level, under
+/// the given `y`-axis scheme
+pub fn meters_to_pixels(xy: XY, z: i32, tile_size: f64, scheme: Scheme) -> (f64, f64) {
+    let res = resolution(z, tile_size);
+    let px = (xy.x + CE / 2.0) / res;
+    let py = match scheme {
+        // TMS counts pixel rows from the south, the same direction mercator y increases in.
+        Scheme::Tms => (xy.y + CE / 2.0) / res,
+        // XYZ counts pixel rows from the north, so the row order is flipped.
+        Scheme::Xyz => (CE / 2.0 - xy.y) / res,
+    };
+
+    (px, py)
+}
+
+/// Converts pixel coordinates into the tile column/row containing them
+pub fn pixels_to_tile(px: f64, py: f64, tile_size: f64) -> (i32, i32) {
+    let tx = (px / tile_size).floor() as i32;
+    let ty = (py / tile_size).floor() as i32;
 
-    LngLat { lng, lat }
+    (tx, ty)
+}
+
+/// Returns whether a tile lies within the bbox's tile coverage at the tile's own zoom level,
+/// i.e. the range of tiles spanned by the bbox's NW and SE corners; a tile the bbox only
+/// partly overlaps counts as contained
+pub fn contains_tile(bbox: &LngLatBbox, tile: Tile) -> bool {
+    TileRange::from_bbox(bbox, tile.z).contains(tile)
+}
+
+/// A rectangular range of tile coordinates at a single zoom level, as produced by covering a
+/// [`LngLatBbox`]. `min_x > max_x` means the range wraps through `2^z`, which happens when the
+/// source bbox straddles the antimeridian.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TileRange {
+    pub z: i32,
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+}
+
+impl TileRange {
+    /// Builds the tile-coordinate range covering a bounding box's NW/SE corners at `z`
+    pub fn from_bbox(bbox: &LngLatBbox, z: i32) -> Self {
+        let nw = tile(bbox.west, bbox.north, z);
+        let se = tile(bbox.east, bbox.south, z);
+
+        TileRange { z, min_x: nw.x, min_y: nw.y, max_x: se.x, max_y: se.y }
+    }
+
+    /// Checks in O(1) whether a tile falls within the range
+    pub fn contains(&self, tile: Tile) -> bool {
+        if tile.z != self.z {
+            return false;
+        }
+
+        let x_in_range = if self.min_x <= self.max_x {
+            self.min_x <= tile.x && tile.x <= self.max_x
+        } else {
+            tile.x >= self.min_x || tile.x <= self.max_x
+        };
+
+        x_in_range && self.min_y <= tile.y && tile.y <= self.max_y
+    }
+
+    /// Returns the number of distinct `x` columns spanned by the range, accounting for wrap
+    fn width(&self) -> i32 {
+        if self.min_x <= self.max_x {
+            self.max_x - self.min_x + 1
+        } else {
+            let (_, hi) = minmax(self.z);
+            (hi - self.min_x + 1) + (self.max_x + 1)
+        }
+    }
+
+    /// The `x` column `offset` columns past `min_x`, wrapping through `2^z` if the range does
+    fn x_at(&self, offset: i32) -> i32 {
+        if self.min_x <= self.max_x {
+            return self.min_x + offset;
+        }
+
+        let (_, hi) = minmax(self.z);
+        let before_wrap = hi - self.min_x + 1;
+        if offset < before_wrap {
+            self.min_x + offset
+        } else {
+            offset - before_wrap
+        }
+    }
+
+    /// Returns an iterator over every tile in the range
+    pub fn iter(&self) -> TileRangeIter {
+        TileRangeIter { range: *self, offset: 0, width: self.width(), y: self.min_y }
+    }
+}
+
+impl IntoIterator for TileRange {
+    type Item = Tile;
+    type IntoIter = TileRangeIter;
+
+    fn into_iter(self) -> TileRangeIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the tiles covered by a [`TileRange`]
+pub struct TileRangeIter {
+    range: TileRange,
+    offset: i32,
+    width: i32,
+    y: i32,
+}
+
+impl Iterator for TileRangeIter {
+    type Item = Tile;
+
+    fn next(&mut self) -> Option<Tile> {
+        if self.y > self.range.max_y {
+            return None;
+        }
+
+        let x = self.range.x_at(self.offset);
+        let result = Tile::new(x, self.y, self.range.z);
+
+        self.offset += 1;
+        if self.offset >= self.width {
+            self.offset = 0;
+            self.y += 1;
+        }
+
+        Some(result)
+    }
+}
+
+/// Returns the parent of a tile, i.e. the tile at `z - 1` that contains it.
+///
+/// Returns `None` for a tile at zoom `0`, which has no parent.
+pub fn parent(tile: Tile) -> Option<Tile> {
+    if tile.z == 0 {
+        return None;
+    }
+
+    Some(Tile::new(tile.x >> 1, tile.y >> 1, tile.z - 1))
+}
+
+/// Returns the four tiles at `z + 1` that make up a tile's children
+pub fn children(tile: Tile) -> Vec<Tile> {
+    let z = tile.z + 1;
+    let x = tile.x * 2;
+    let y = tile.y * 2;
+
+    vec![
+        Tile::new(x, y, z),
+        Tile::new(x + 1, y, z),
+        Tile::new(x, y + 1, z),
+        Tile::new(x + 1, y + 1, z),
+    ]
+}
+
+/// Returns the smallest tile that fully contains a geographical bounding box.
+///
+/// Finds the tile coordinates of the box's NW and SE corners at a high zoom level, then
+/// walks both up through `parent` until they land on the same tile.
+pub fn bounding_tile(bbox: LngLatBbox) -> Tile {
+    const MAX_ZOOM: i32 = 28;
+
+    let mut nw = tile(bbox.west, bbox.north, MAX_ZOOM);
+    let mut se = tile(bbox.east, bbox.south, MAX_ZOOM);
+
+    while nw.x != se.x || nw.y != se.y {
+        nw = parent(nw).expect("the zoom 0 tile covers the whole world");
+        se = parent(se).expect("the zoom 0 tile covers the whole world");
+    }
+
+    nw
+}
+
+/// Returns every tile that intersects a geographical bounding box at the given zoom levels.
+///
+/// Boxes that cross the antimeridian (`bbox.west > bbox.east`) are split into an eastern
+/// and a western half, each covered independently and the results unioned. Latitudes are
+/// clamped to the range the web mercator projection can represent so that boxes touching
+/// the poles don't produce out-of-range rows.
+pub fn tiles(bbox: LngLatBbox, zooms: &[i32]) -> Vec<Tile> {
+    let bboxes = if bbox.west > bbox.east {
+        vec![
+            LngLatBbox { west: bbox.west, south: bbox.south, east: 180.0, north: bbox.north },
+            LngLatBbox { west: -180.0, south: bbox.south, east: bbox.east, north: bbox.north },
+        ]
+    } else {
+        vec![bbox]
+    };
+
+    let mut result = Vec::new();
+
+    for bb in bboxes {
+        let north = bb.north.clamp(-MAX_LATITUDE, MAX_LATITUDE);
+        let south = bb.south.clamp(-MAX_LATITUDE, MAX_LATITUDE);
+
+        for &z in zooms {
+            let ul_tile = tile(bb.west, north, z);
+            let lr_tile = tile(bb.east, south, z);
+
+            for x in ul_tile.x..=lr_tile.x {
+                for y in ul_tile.y..=lr_tile.y {
+                    result.push(Tile::new(x, y, z));
+                }
+            }
+        }
+    }
+
+    result
 }
 
 /// Get neighbor tiles for specific tiles
@@ -285,6 +701,337 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_tile() {
+        let result = tile(20.6852, 40.1222, 9);
+        let expected = Tile::new(285, 193, 9);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_tile_clamps_poles() {
+        let result = tile(-181.0, 89.9999999, 2);
+        let expected = Tile::new(0, 0, 2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_quadkey() {
+        let tile = Tile::new(486, 332, 10);
+        let result = quadkey(tile);
+
+        assert_eq!(result, "0313102310");
+    }
+
+    #[test]
+    fn test_quadkey_to_tile() {
+        let result = quadkey_to_tile("0313102310");
+        let expected = Tile::new(486, 332, 10);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "quadkey digits must be in the range 0-3")]
+    fn test_quadkey_to_tile_rejects_invalid_digit() {
+        quadkey_to_tile("4");
+    }
+
+    #[test]
+    fn test_quadkey_round_trip() {
+        let tile = Tile::new(486, 332, 10);
+        let result = quadkey_to_tile(&quadkey(tile));
+
+        assert_eq!(result, tile);
+    }
+
+    #[test]
+    fn test_tiles() {
+        let bbox = LngLatBbox { west: -9.13, south: 53.13, east: -8.80, north: 53.32 };
+        let result = tiles(bbox, &[10]);
+        let expected = vec![Tile::new(486, 332, 10)];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_tiles_antimeridian() {
+        let bbox = LngLatBbox { west: 175.0, south: -5.0, east: -175.0, north: 5.0 };
+        let result = tiles(bbox, &[2]);
+
+        assert!(result.contains(&Tile::new(3, 1, 2)));
+        assert!(result.contains(&Tile::new(0, 1, 2)));
+    }
+
+    #[test]
+    fn test_tiles_clamps_poles() {
+        let bbox = LngLatBbox { west: -180.0, south: -90.0, east: 180.0, north: 90.0 };
+        let result = tiles(bbox, &[0]);
+
+        assert_eq!(result, vec![Tile::new(0, 0, 0)]);
+    }
+
+    #[test]
+    fn test_contains_tile() {
+        let bbox = LngLatBbox { west: -9.13, south: 53.13, east: -8.80, north: 53.32 };
+
+        assert!(contains_tile(&bbox, Tile::new(486, 332, 10)));
+        assert!(!contains_tile(&bbox, Tile::new(0, 0, 10)));
+    }
+
+    #[test]
+    fn test_tile_range_contains() {
+        let range = TileRange { z: 10, min_x: 485, min_y: 330, max_x: 487, max_y: 333 };
+
+        assert!(range.contains(Tile::new(486, 332, 10)));
+        assert!(!range.contains(Tile::new(488, 332, 10)));
+        assert!(!range.contains(Tile::new(486, 332, 9)));
+    }
+
+    #[test]
+    fn test_tile_range_iter() {
+        let range = TileRange { z: 1, min_x: 0, min_y: 0, max_x: 1, max_y: 0 };
+        let result: Vec<Tile> = range.iter().collect();
+        let expected = vec![Tile::new(0, 0, 1), Tile::new(1, 0, 1)];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_tile_range_wraps_antimeridian() {
+        let (_, hi) = minmax(2);
+        let range = TileRange { z: 2, min_x: hi, min_y: 0, max_x: 0, max_y: 0 };
+        let result: Vec<Tile> = range.iter().collect();
+
+        assert_eq!(result, vec![Tile::new(hi, 0, 2), Tile::new(0, 0, 2)]);
+        assert!(range.contains(Tile::new(hi, 0, 2)));
+        assert!(range.contains(Tile::new(0, 0, 2)));
+        assert!(!range.contains(Tile::new(1, 0, 2)));
+    }
+
+    #[test]
+    fn test_truncate_lnglat() {
+        let result = truncate_lnglat(200.0, 100.0);
+        let expected = LngLat { lng: 180.0, lat: MAX_LATITUDE };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_tile_with_truncate() {
+        let result = tile_with_truncate(-181.0, 89.9999999, 2, true);
+        let expected = tile(-180.0, MAX_LATITUDE, 2);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_xy_round_trip_grid() {
+        for lng in -180..=180 {
+            for lat in -85..=85 {
+                let original = LngLat { lng: lng as f64, lat: lat as f64 };
+                let xy = convert_xy(LngLat { lng: original.lng, lat: original.lat });
+                let round_tripped = convert_lng_lat(xy);
+
+                assert!((round_tripped.lng - original.lng).abs() < 1e-9);
+                assert!((round_tripped.lat - original.lat).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tile_bounds_center_round_trip_grid() {
+        for lng in (-180..180).step_by(10) {
+            for lat in (-80..=80).step_by(10) {
+                let t = tile(lng as f64, lat as f64, 12);
+                let b = bounds(t);
+                let center_lng = (b.west + b.east) / 2.0;
+                let center_lat = (b.south + b.north) / 2.0;
+                let t2 = tile(center_lng, center_lat, 12);
+
+                assert_eq!(t2, t);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parent() {
+        let tile = Tile::new(486, 332, 10);
+        let result = parent(tile);
+        let expected = Some(Tile::new(243, 166, 9));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parent_of_root_is_none() {
+        let tile = Tile::new(0, 0, 0);
+
+        assert_eq!(parent(tile), None);
+    }
+
+    #[test]
+    fn test_children() {
+        let tile = Tile::new(243, 166, 9);
+        let result = children(tile);
+        let expected = vec![
+            Tile::new(486, 332, 10),
+            Tile::new(487, 332, 10),
+            Tile::new(486, 333, 10),
+            Tile::new(487, 333, 10),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_children_parent_round_trip() {
+        let tile = Tile::new(486, 332, 10);
+
+        assert!(children(parent(tile).unwrap()).contains(&tile));
+    }
+
+    #[test]
+    fn test_bounding_tile() {
+        let bbox = LngLatBbox { west: -9.13, south: 53.13, east: -8.80, north: 53.32 };
+        let result = bounding_tile(bbox);
+        let expected = Tile::new(486, 332, 10);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bounding_tile_whole_world_is_root() {
+        let bbox = LngLatBbox { west: -180.0, south: -90.0, east: 180.0, north: 90.0 };
+        let result = bounding_tile(bbox);
+        let expected = Tile::new(0, 0, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_geodetic_tile_allows_eastern_hemisphere() {
+        // The geodetic level-zero grid is 2 columns wide, so x=1 (east of the prime
+        // meridian) is a valid z0 tile even though it's out of range for web mercator.
+        let tile = Tile::new_with_projection(1, 0, 0, Projection::Geodetic);
+
+        assert_eq!(tile, Tile { x: 1, y: 0, z: 0 });
+    }
+
+    #[test]
+    fn test_geodetic_tile_accepts_beyond_mercator_range() {
+        let tile = Tile::new_with_projection(3, 1, 1, Projection::Geodetic);
+
+        assert_eq!(tile, Tile { x: 3, y: 1, z: 1 });
+    }
+
+    #[test]
+    fn test_bounds_geodetic_eastern_hemisphere() {
+        let tile = Tile::new_with_projection(1, 0, 0, Projection::Geodetic);
+        let result = bounds_with_projection(tile, Projection::Geodetic);
+        let expected = LngLatBbox { west: 0.0, south: -90.0, east: 180.0, north: 90.0 };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ul_geodetic() {
+        let tile = Tile::new(1, 0, 1);
+        let result = ul_with_projection(tile, Projection::Geodetic);
+        let expected = LngLat { lng: -90.0, lat: 90.0 };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bounds_geodetic() {
+        let tile = Tile::new(0, 0, 0);
+        let result = bounds_with_projection(tile, Projection::Geodetic);
+        let expected = LngLatBbox { west: -180.0, south: -90.0, east: 0.0, north: 90.0 };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_xy_bounds_geodetic_is_degrees() {
+        let tile = Tile::new(0, 0, 0);
+        let result = xy_bounds_with_projection(tile, Projection::Geodetic);
+        let expected = Bbox { left: -180.0, bottom: -90.0, right: 0.0, top: 90.0 };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_convert_xy_geodetic_is_identity() {
+        let lng_lat = LngLat { lng: 12.5, lat: -33.25 };
+        let result = convert_xy_with_projection(lng_lat, Projection::Geodetic);
+        let expected = XY { x: 12.5, y: -33.25 };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ul_web_mercator_matches_default() {
+        let tile = Tile::new(486, 332, 10);
+
+        assert_eq!(ul_with_projection(tile, Projection::WebMercator), ul(tile));
+    }
+
+    #[test]
+    fn test_flip_y() {
+        let tile = Tile::new(486, 332, 10);
+        let result = flip_y(tile);
+        let expected = Tile::new(486, 691, 10);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_flip_y_round_trip() {
+        let tile = Tile::new(486, 332, 10);
+        let result = flip_y(flip_y(tile));
+
+        assert_eq!(result, tile);
+    }
+
+    #[test]
+    fn test_resolution() {
+        let result = resolution(0, DEFAULT_TILE_SIZE);
+        let expected = CE / DEFAULT_TILE_SIZE;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_zoom_for_pixel_size() {
+        let z = zoom_for_pixel_size(resolution(14, DEFAULT_TILE_SIZE), DEFAULT_TILE_SIZE);
+
+        assert_eq!(z, 14);
+    }
+
+    #[test]
+    fn test_meters_to_pixels_to_tile_tms() {
+        let xy = XY { x: -1017529.7205322663, y: 7044436.526761846 };
+        let (px, py) = meters_to_pixels(xy, 10, DEFAULT_TILE_SIZE, Scheme::Tms);
+        let (tx, ty) = pixels_to_tile(px, py, DEFAULT_TILE_SIZE);
+
+        assert_eq!((tx, ty), (486, 692));
+    }
+
+    #[test]
+    fn test_meters_to_pixels_xyz_flips_row_vs_tms() {
+        let xy = XY { x: -1017529.7205322663, y: 7044436.526761846 };
+        let (px_tms, py_tms) = meters_to_pixels(xy, 10, DEFAULT_TILE_SIZE, Scheme::Tms);
+        let xy = XY { x: -1017529.7205322663, y: 7044436.526761846 };
+        let (px_xyz, py_xyz) = meters_to_pixels(xy, 10, DEFAULT_TILE_SIZE, Scheme::Xyz);
+
+        let total_pixels = DEFAULT_TILE_SIZE * 2.0_f64.powf(10.0);
+        assert_eq!(px_tms, px_xyz);
+        assert!((py_xyz - (total_pixels - py_tms)).abs() < 1e-6);
+    }
+
     #[test]
     fn test_get_neighbors() {
         let tile = Tile::new(486, 332, 10);